@@ -52,6 +52,16 @@ pub enum CliCommand {
         /// The timelock delay of the DAO's created proposals.
         #[clap(long)]
         timelock_delay_seconds: i64,
+        /// The minimum time between a proposal succeeding and it becoming queueable, in seconds
+        #[clap(long, default_value_t = 0)]
+        post_finalize_cooloff_seconds: u64,
+        /// The minimum share of votes in favor required for a proposal to succeed, in basis points.
+        /// Zero only requires more votes for than against
+        #[clap(long, default_value_t = 0)]
+        approval_ratio_bps: u64,
+        /// The minimum share of the total voting power that must vote on a proposal, in basis points
+        #[clap(long, default_value_t = 0)]
+        min_participation_bps: u64,
     },
     CreateDummyProposal {
         #[clap(long)]
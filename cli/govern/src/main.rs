@@ -43,6 +43,9 @@ fn main() -> Result<()> {
             voting_period,
             quorum_votes,
             timelock_delay_seconds,
+            post_finalize_cooloff_seconds,
+            approval_ratio_bps,
+            min_participation_bps,
         } => {
             create_governor(
                 &program,
@@ -51,6 +54,9 @@ fn main() -> Result<()> {
                 voting_period,
                 quorum_votes,
                 timelock_delay_seconds,
+                post_finalize_cooloff_seconds,
+                approval_ratio_bps,
+                min_participation_bps,
             )?;
         }
         CliCommand::CreateDummyProposal { base } => {
@@ -101,6 +107,9 @@ fn create_governor(
     voting_period: u64,
     quorum_votes: u64,
     timelock_delay_seconds: i64,
+    post_finalize_cooloff_seconds: u64,
+    approval_ratio_bps: u64,
+    min_participation_bps: u64,
 ) -> Result<()> {
     let base = base_keypair.pubkey();
 
@@ -133,6 +142,9 @@ fn create_governor(
                 voting_period,
                 quorum_votes,
                 timelock_delay_seconds,
+                post_finalize_cooloff_seconds,
+                approval_ratio_bps,
+                min_participation_bps,
             },
         })
         .signer(&base_keypair);
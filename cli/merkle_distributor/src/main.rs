@@ -225,6 +225,14 @@ fn claim(
         ],
         &voter::id(),
     );
+    let (withdraw_record, _bump) = Pubkey::find_program_address(
+        &[
+            b"WithdrawRecord".as_ref(),
+            distributor_state.locker.as_ref(),
+            claimant.as_ref(),
+        ],
+        &voter::id(),
+    );
     // check whether escrow is created
     let mut instructions = vec![];
     let escrow_info = program.rpc().get_account(&escrow);
@@ -234,6 +242,7 @@ fn claim(
                 locker: distributor_state.locker,
                 escrow,
                 escrow_owner: claimant,
+                withdraw_record,
                 payer: program.payer(),
                 system_program: solana_program::system_program::ID,
             }
@@ -93,6 +93,9 @@ fn main() -> Result<()> {
             voting_period,
             quorum_votes,
             timelock_delay_seconds,
+            post_finalize_cooloff_seconds,
+            approval_ratio_bps,
+            min_participation_bps,
         } => {
             create_set_governance_params_tx(
                 &program,
@@ -101,6 +104,9 @@ fn main() -> Result<()> {
                 voting_period,
                 quorum_votes,
                 timelock_delay_seconds,
+                post_finalize_cooloff_seconds,
+                approval_ratio_bps,
+                min_participation_bps,
             )?;
         }
     }
@@ -252,6 +258,9 @@ fn create_set_governance_params_tx(
     voting_period: u64,
     quorum_votes: u64,
     timelock_delay_seconds: i64,
+    post_finalize_cooloff_seconds: u64,
+    approval_ratio_bps: u64,
+    min_participation_bps: u64,
 ) -> Result<()> {
     let (smart_wallet, bump) = Pubkey::find_program_address(
         &[b"SmartWallet".as_ref(), base.as_ref()],
@@ -267,6 +276,9 @@ fn create_set_governance_params_tx(
             voting_period,
             quorum_votes,
             timelock_delay_seconds,
+            post_finalize_cooloff_seconds,
+            approval_ratio_bps,
+            min_participation_bps,
         },
     }
     .data();
@@ -297,6 +309,7 @@ fn create_activate_proposal_tx(program: &Program, base: Pubkey, proposal: Pubkey
     );
     let proposal_state: govern::Proposal = program.account(proposal)?;
     let governor_state: govern::Governor = program.account(proposal_state.governor)?;
+    let locker_state: voter::Locker = program.account(governor_state.locker)?;
 
     let data = voter::instruction::ActivateProposalInitialPhase {}.data();
     let instruction = smart_wallet::TXInstruction {
@@ -317,6 +330,11 @@ fn create_activate_proposal_tx(program: &Program, base: Pubkey, proposal: Pubkey
                 is_signer: false,
                 is_writable: true,
             },
+            smart_wallet::TXAccountMeta {
+                pubkey: locker_state.token_mint,
+                is_signer: false,
+                is_writable: false,
+            },
             smart_wallet::TXAccountMeta {
                 pubkey: govern::id(),
                 is_signer: false,
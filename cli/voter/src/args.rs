@@ -55,6 +55,9 @@ pub enum CliCommand {
         /// Minimum number of votes required to activate a proposal.
         #[clap(long)]
         proposal_activation_min_votes: u64,
+        /// Seconds an owner must wait after a withdrawal before creating a new escrow.
+        #[clap(long, default_value_t = 0)]
+        relock_cooldown_seconds: u64,
     },
     NewEscrow {
         #[clap(long)]
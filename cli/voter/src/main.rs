@@ -48,6 +48,7 @@ fn main() -> Result<()> {
             min_stake_duration,
             max_stake_duration,
             proposal_activation_min_votes,
+            relock_cooldown_seconds,
         } => {
             new_locker(
                 &program,
@@ -58,6 +59,7 @@ fn main() -> Result<()> {
                 min_stake_duration,
                 max_stake_duration,
                 proposal_activation_min_votes,
+                relock_cooldown_seconds,
             )?;
         }
         CliCommand::NewEscrow { base } => {
@@ -129,6 +131,7 @@ fn new_locker(
     min_stake_duration: u64,
     max_stake_duration: u64,
     proposal_activation_min_votes: u64,
+    relock_cooldown_seconds: u64,
 ) -> Result<()> {
     let base = base_keypair.pubkey();
     let (governor, bump) =
@@ -155,6 +158,7 @@ fn new_locker(
                 min_stake_duration,
                 max_stake_duration,
                 proposal_activation_min_votes,
+                relock_cooldown_seconds,
             },
         })
         .signer(&base_keypair);
@@ -173,12 +177,22 @@ fn new_escrow(program: &Program, locker: Pubkey) -> Result<()> {
         &voter::id(),
     );
 
+    let (withdraw_record, _bump) = Pubkey::find_program_address(
+        &[
+            b"WithdrawRecord".as_ref(),
+            locker.as_ref(),
+            program.payer().as_ref(),
+        ],
+        &voter::id(),
+    );
+
     let builder = program
         .request()
         .accounts(voter::accounts::NewEscrow {
             locker,
             escrow,
             escrow_owner: program.payer(),
+            withdraw_record,
             payer: program.payer(),
             system_program: solana_program::system_program::ID,
         })
@@ -255,6 +269,14 @@ fn withdraw(program: &Program, locker: Pubkey) -> Result<()> {
 
     let destination_tokens =
         get_associated_token_address(&program.payer(), &locker_state.token_mint);
+    let (withdraw_record, _bump) = Pubkey::find_program_address(
+        &[
+            b"WithdrawRecord".as_ref(),
+            locker.as_ref(),
+            program.payer().as_ref(),
+        ],
+        &voter::id(),
+    );
 
     let builder = program
         .request()
@@ -265,7 +287,9 @@ fn withdraw(program: &Program, locker: Pubkey) -> Result<()> {
             destination_tokens,
             escrow_owner: program.payer(),
             payer: program.payer(),
+            withdraw_record,
             token_program: anchor_spl::token::ID,
+            system_program: solana_program::system_program::ID,
         })
         .args(voter::instruction::Withdraw {});
     let signature = builder.send()?;
@@ -290,6 +314,7 @@ fn active_proposal(program: &Program, locker: Pubkey, proposal: Pubkey) -> Resul
             locker,
             escrow,
             proposal,
+            token_mint: locker_state.token_mint,
             escrow_owner: program.payer(),
             governor: locker_state.governor,
             govern_program: govern::ID,
@@ -13,10 +13,14 @@ pub struct ActivateProposal<'info> {
 }
 
 impl<'info> ActivateProposal<'info> {
-    pub fn activate_proposal(&mut self) -> Result<()> {
+    pub fn activate_proposal(&mut self, total_voting_power: u64) -> Result<()> {
         let proposal = &mut self.proposal;
         let now = Clock::get()?.unix_timestamp;
         proposal.activated_at = now;
+        proposal.min_participation_votes = unwrap_int!(self
+            .governor
+            .params
+            .min_participation_votes(total_voting_power));
         proposal.voting_ends_at = unwrap_int!(self
             .governor
             .params
@@ -28,6 +32,7 @@ impl<'info> ActivateProposal<'info> {
             governor: proposal.governor,
             proposal: proposal.key(),
             voting_ends_at: proposal.voting_ends_at,
+            min_participation_votes: proposal.min_participation_votes,
         });
 
         Ok(())
@@ -38,10 +43,9 @@ impl<'info> Validate<'info> for ActivateProposal<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.governor, self.proposal.governor);
         assert_keys_eq!(self.locker, self.governor.locker);
-        invariant!(
-            self.proposal.get_state()? == ProposalState::Draft,
-            ProposalNotDraft
-        );
+        // activating again would reset the voting period
+        invariant!(self.proposal.canceled_at == 0, ProposalCanceled);
+        invariant!(self.proposal.activated_at == 0, ProposalAlreadyActive);
 
         let earliest_activation_time = unwrap_int!(self
             .governor
@@ -73,4 +77,6 @@ pub struct ProposalActivateEvent {
     pub proposal: Pubkey,
     /// When voting ends for the [Proposal].
     pub voting_ends_at: i64,
+    /// The number of votes required to meet the minimum participation.
+    pub min_participation_votes: u64,
 }
@@ -4,6 +4,7 @@ use crate::*;
 #[derive(Accounts)]
 pub struct CancelProposal<'info> {
     /// The [Governor].
+    #[account(mut)]
     pub governor: Account<'info, Governor>,
     /// The [Proposal] to activate.
     #[account(mut)]
@@ -17,6 +18,9 @@ impl<'info> CancelProposal<'info> {
         let proposal = &mut self.proposal;
         proposal.canceled_at = Clock::get()?.unix_timestamp;
 
+        let governor = &mut self.governor;
+        governor.record_proposal_canceled()?;
+
         emit!(ProposalCancelEvent {
             governor: proposal.governor,
             proposal: proposal.key(),
@@ -0,0 +1,67 @@
+use crate::*;
+
+/// Accounts for [govern::close_votes].
+#[derive(Accounts)]
+pub struct CloseVotes<'info> {
+    /// The [Vote::voter]. Receives the rent of every closed [Vote].
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+impl<'info> CloseVotes<'info> {
+    /// Closes the [Vote]s passed in `remaining_accounts` as `[vote, proposal]` pairs.
+    ///
+    /// Votes on proposals which may still be voted on are skipped rather than failing the batch.
+    pub fn close_votes(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        invariant!(
+            remaining_accounts.len() % 2 == 0,
+            "remaining accounts must be vote and proposal pairs"
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut closed_count: u64 = 0;
+        let mut skipped_count: u64 = 0;
+        for pair in remaining_accounts.chunks(2) {
+            let vote: Account<Vote> = Account::try_from(&pair[0])?;
+            let proposal: Account<Proposal> = Account::try_from(&pair[1])?;
+            assert_keys_eq!(vote.voter, self.voter, "vote should belong to the voter");
+            assert_keys_eq!(vote.proposal, proposal, "vote proposal should match");
+
+            let proposal_state = unwrap_opt!(proposal.state(now), "invalid state");
+            if matches!(proposal_state, ProposalState::Draft | ProposalState::Active) {
+                msg!("vote {} is still votable; skipping", vote.key());
+                skipped_count = unwrap_int!(skipped_count.checked_add(1));
+                continue;
+            }
+
+            vote.close(self.voter.to_account_info())?;
+            closed_count = unwrap_int!(closed_count.checked_add(1));
+        }
+
+        emit!(VotesCloseEvent {
+            voter: self.voter.key(),
+            closed_count,
+            skipped_count,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CloseVotes<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [govern::close_votes].
+#[event]
+pub struct VotesCloseEvent {
+    /// The voter.
+    #[index]
+    pub voter: Pubkey,
+    /// The number of [Vote]s closed.
+    pub closed_count: u64,
+    /// The number of [Vote]s skipped because their [Proposal] may still be voted on.
+    pub skipped_count: u64,
+}
@@ -33,16 +33,14 @@ impl<'info> CreateGovernor<'info> {
         locker: Pubkey,
         params: GovernanceParameters,
     ) -> Result<()> {
-        invariant!(
-            params.timelock_delay_seconds >= 0,
-            "timelock delay must be at least 0 seconds"
-        );
+        params.validate()?;
 
         let governor = &mut self.governor;
         governor.base = self.base.key();
         governor.bump = bump;
 
         governor.proposal_count = 0;
+        governor.effective_proposal_count = 0;
         governor.locker = locker;
         governor.smart_wallet = self.smart_wallet.key();
 
@@ -39,12 +39,13 @@ impl<'info> CreateProposal<'info> {
 
         let proposal = &mut self.proposal;
         proposal.governor = governor.key();
-        proposal.index = governor.proposal_count;
+        proposal.index = governor.record_proposal_created()?;
         proposal.bump = bump;
 
         proposal.proposer = self.proposer.key();
 
         proposal.quorum_votes = governor.params.quorum_votes;
+        proposal.approval_ratio_bps = governor.params.approval_ratio_bps;
         proposal.created_at = Clock::get()?.unix_timestamp;
         proposal.canceled_at = 0;
         proposal.activated_at = 0;
@@ -55,8 +56,6 @@ impl<'info> CreateProposal<'info> {
 
         proposal.instructions = instructions.clone();
 
-        governor.proposal_count += 1;
-
         emit!(ProposalCreateEvent {
             governor: governor.key(),
             proposal: proposal.key(),
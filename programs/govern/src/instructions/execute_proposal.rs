@@ -0,0 +1,91 @@
+use crate::*;
+
+/// Accounts for [govern::execute_proposal].
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// The [Governor]. Executes the transaction as an owner of the [SmartWallet].
+    #[account(has_one = smart_wallet)]
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal] to execute.
+    pub proposal: Account<'info, Proposal>,
+    /// The [SmartWallet].
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Proposal::queued_transaction].
+    #[account(mut)]
+    pub transaction: Account<'info, smart_wallet::Transaction>,
+    /// The Smart Wallet program.
+    pub smart_wallet_program: Program<'info, smart_wallet::program::SmartWallet>,
+}
+
+impl<'info> ExecuteProposal<'info> {
+    /// Executes the queued transaction of the [Proposal] on the Smart Wallet.
+    ///
+    /// The accounts of the [Proposal::instructions] are passed in `remaining_accounts`.
+    pub fn execute_proposal(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let seeds = governor_seeds!(self.governor);
+        let signer_seeds = &[&seeds[..]];
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.smart_wallet_program.to_account_info(),
+            smart_wallet::cpi::accounts::ExecuteTransaction {
+                smart_wallet: self.smart_wallet.to_account_info(),
+                transaction: self.transaction.to_account_info(),
+                owner: self.governor.to_account_info(),
+            },
+            signer_seeds,
+        )
+        .with_remaining_accounts(remaining_accounts.to_vec());
+
+        // the Smart Wallet only executes a transaction once,
+        // so this cannot emit twice for the same proposal.
+        smart_wallet::cpi::execute_transaction(cpi_ctx)?;
+
+        emit!(ProposalExecutedEvent {
+            governor: self.governor.key(),
+            proposal: self.proposal.key(),
+            index: self.proposal.index,
+            executed_at: Clock::get()?.unix_timestamp,
+            instruction_count: self.proposal.instructions.len() as u64,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ExecuteProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.governor, self.proposal.governor);
+        assert_keys_eq!(
+            self.transaction,
+            self.proposal.queued_transaction,
+            "transaction should be the queued transaction"
+        );
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Queued,
+            "proposal must be queued to be executed"
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::execute_proposal].
+///
+/// This is only emitted when the [Proposal] is executed through [govern::execute_proposal].
+/// Any other owner of the [SmartWallet] may execute the [Proposal::queued_transaction] directly
+/// via [smart_wallet::execute_transaction], in which case no event is emitted; integrators
+/// that must observe every execution should also watch [smart_wallet::TransactionExecuteEvent]
+/// for the queued transaction.
+#[event]
+pub struct ProposalExecutedEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being executed.
+    #[index]
+    pub proposal: Pubkey,
+    /// The index of the [Proposal].
+    pub index: u64,
+    /// When the [Proposal] was executed.
+    pub executed_at: i64,
+    /// The number of instructions executed.
+    pub instruction_count: u64,
+}
@@ -79,30 +79,10 @@ impl<'info> Validate<'info> for QueueProposal<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.governor, self.proposal.governor);
         assert_keys_eq!(self.smart_wallet, self.governor.smart_wallet);
-        let now = Clock::get()?.unix_timestamp;
-        let proposal_state = unwrap_opt!(self.proposal.state(now), "invalid state");
-        if proposal_state != ProposalState::Succeeded {
-            msg!(
-                "now: {}, voting_ends_at: {}",
-                now,
-                self.proposal.voting_ends_at
-            );
-            msg!(
-                "for votes: {}, against votes: {}",
-                self.proposal.for_votes,
-                self.proposal.against_votes,
-            );
-            msg!(
-                "quorum req: {}, abstain votes: {}",
-                self.governor.params.quorum_votes,
-                self.proposal.abstain_votes,
-            );
-            invariant!(
-                proposal_state == ProposalState::Succeeded,
-                "proposal must be succeeded to be queued"
-            );
-        }
-        Ok(())
+        self.proposal.assert_queueable(
+            Clock::get()?.unix_timestamp,
+            self.governor.params.post_finalize_cooloff_seconds,
+        )
     }
 }
 
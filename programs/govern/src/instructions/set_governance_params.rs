@@ -11,6 +11,8 @@ pub struct SetGovernanceParams<'info> {
 
 impl<'info> SetGovernanceParams<'info> {
     pub fn set_governance_params(&mut self, params: GovernanceParameters) -> Result<()> {
+        params.validate()?;
+
         let prev_params = self.governor.params;
         self.governor.params = params;
 
@@ -44,6 +46,7 @@ impl<'info> Validate<'info> for SetGovernanceParams<'info> {
             self.governor.smart_wallet,
             "smart wallet should match"
         );
+        self.governor.assert_pda(&self.governor.key())?;
         Ok(())
     }
 }
@@ -16,17 +16,13 @@ pub struct SetVote<'info> {
 }
 
 impl<'info> SetVote<'info> {
-    /// Queues a Transaction into the Smart Wallet.
+    /// Sets the side of the [Vote].
+    ///
+    /// `weight` is only used the first time the [Vote] is cast; see [Proposal::record_vote].
     pub fn set_vote(&mut self, side: u8, weight: u64) -> Result<()> {
-        let vote = &self.vote;
-
         let proposal = &mut self.proposal;
-        proposal.subtract_vote_weight(vote.side.try_into()?, vote.weight)?;
-        proposal.add_vote_weight(side.try_into()?, weight)?;
-
         let vote = &mut self.vote;
-        vote.side = side;
-        vote.weight = weight;
+        let weight = proposal.record_vote(vote, side, weight)?;
 
         emit!(VoteSetEvent {
             governor: proposal.governor,
@@ -51,9 +51,16 @@ pub mod govern {
     /// Activates a proposal.
     /// Only the [Governor::voter] may call this; that program
     /// may ensure that only certain types of users can activate proposals.
+    ///
+    /// The locker also provides the total voting power, which is used to
+    /// snapshot the [Proposal::min_participation_votes]. It may pass zero when
+    /// [GovernanceParameters::min_participation_bps] is zero.
     #[access_control(ctx.accounts.validate())]
-    pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
-        ctx.accounts.activate_proposal()
+    pub fn activate_proposal(
+        ctx: Context<ActivateProposal>,
+        total_voting_power: u64,
+    ) -> Result<()> {
+        ctx.accounts.activate_proposal(total_voting_power)
     }
 
     /// Cancels a proposal.
@@ -71,6 +78,19 @@ pub mod govern {
         Ok(())
     }
 
+    /// Executes a queued proposal through the [SmartWallet], emitting a [ProposalExecutedEvent].
+    /// Anyone can call this once the Smart Wallet transaction is ready to be executed.
+    /// The accounts of the proposal instructions are passed as the remaining accounts.
+    ///
+    /// Other owners of the [SmartWallet] can still execute the queued transaction directly,
+    /// bypassing this instruction and its event.
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.execute_proposal(ctx.remaining_accounts)
+    }
+
     /// Creates a new [Vote]. Anyone can call this.
     #[access_control(ctx.accounts.validate())]
     pub fn new_vote(ctx: Context<NewVote>, voter: Pubkey) -> Result<()> {
@@ -84,6 +104,13 @@ pub mod govern {
         ctx.accounts.set_vote(side, weight)
     }
 
+    /// Closes [Vote]s whose [Proposal] can no longer be voted on, refunding their rent to the voter.
+    /// The [Vote] and [Proposal] accounts are passed as pairs in the remaining accounts.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_votes<'info>(ctx: Context<'_, '_, '_, 'info, CloseVotes<'info>>) -> Result<()> {
+        ctx.accounts.close_votes(ctx.remaining_accounts)
+    }
+
     /// Sets the [GovernanceParameters].
     /// This may only be called by the [Governor::smart_wallet].
     #[access_control(ctx.accounts.validate())]
@@ -125,4 +152,12 @@ pub enum ErrorCode {
     ProposalNotDraft,
     #[msg("The proposal must be active.")]
     ProposalNotActive,
+    #[msg("The proposal has already been activated.")]
+    ProposalAlreadyActive,
+    #[msg("The proposal has been canceled.")]
+    ProposalCanceled,
+    #[msg("The proposal cannot be queued until the post-finalize cooloff has elapsed.")]
+    PostFinalizeCooloffNotElapsed,
+    #[msg("The governor does not match the address derived from its base.")]
+    GovernorAddressMismatch,
 }
@@ -24,9 +24,11 @@ pub enum ProposalState {
     /// A canceled proposal cannot be reactivated; it simply just exists as a record.
     Canceled,
     /// After the voting period ends, votes are tallied up. A proposal is [ProposalState::Defeated] if one of
-    /// two scenarios happen:
+    /// four scenarios happen:
     /// - More or equal votes are [VoteSide::Against] than [VoteSide::For].
+    /// - The [VoteSide::For] votes do not meet the [Proposal::approval_ratio_bps].
     /// - The sum of all votes does not meet quorum.
+    /// - The sum of all votes does not meet the [Proposal::min_participation_votes].
     Defeated,
     /// A proposal is [ProposalState::Succeeded] if it is not defeated and voting is over.
     Succeeded,
@@ -125,6 +127,27 @@ impl Proposal {
         Ok(())
     }
 
+    /// Records a [Vote] on this [Proposal], returning the weight that was counted.
+    ///
+    /// The weight is fixed when the [Vote] is first cast on this [Proposal]: changing
+    /// or rescinding the vote later moves that same weight rather than `weight`,
+    /// which may have changed in the meantime.
+    pub(crate) fn record_vote(&mut self, vote: &mut Vote, side: u8, weight: u64) -> Result<u64> {
+        let vote_weight = if vote.weight == 0 {
+            weight
+        } else {
+            vote.weight
+        };
+
+        self.subtract_vote_weight(vote.side.try_into()?, vote.weight)?;
+        self.add_vote_weight(side.try_into()?, vote_weight)?;
+
+        vote.side = side;
+        vote.weight = vote_weight;
+
+        Ok(vote_weight)
+    }
+
     /// Gets the state.
     pub fn get_state(&self) -> Result<ProposalState> {
         Ok(unwrap_opt!(
@@ -144,6 +167,28 @@ impl Proposal {
         )
     }
 
+    /// Checks if enough of the total voting power voted on the proposal;
+    /// see [GovernanceParameters::min_participation_bps].
+    pub fn meets_min_participation(&self) -> Option<bool> {
+        self.meets_quorum(self.min_participation_votes)
+    }
+
+    /// Checks if the proposal meets the approval ratio; that is,
+    /// `for_votes * 10000 >= (for_votes + against_votes) * approval_ratio_bps`.
+    ///
+    /// Both sides are compared exactly, without division, so there is no rounding:
+    /// a ratio of 6667 requires at least 66.67% of the votes, which is slightly more than two thirds.
+    /// For example, 6667 for and 3333 against passes, while 2 for and 1 against does not.
+    /// Abstain votes are not counted.
+    pub fn meets_approval_ratio(&self, approval_ratio_bps: u64) -> Option<bool> {
+        let for_votes = u128::from(self.for_votes);
+        let total_votes = for_votes.checked_add(u128::from(self.against_votes))?;
+        Some(
+            for_votes.checked_mul(u128::from(MAX_BPS))?
+                >= total_votes.checked_mul(u128::from(approval_ratio_bps))?,
+        )
+    }
+
     /// The state of the proposal. See [ProposalState] for more details.
     /// Adapted from <https://github.com/compound-finance/compound-protocol/blob/4a8648ec0364d24c4ecfc7d6cae254f55030d65f/contracts/Governance/GovernorBravoDelegate.sol#L205>
     pub fn state(&self, current_time: i64) -> Option<ProposalState> {
@@ -153,7 +198,11 @@ impl Proposal {
             return Some(ProposalState::Draft);
         } else if current_time < self.voting_ends_at {
             return Some(ProposalState::Active);
-        } else if self.for_votes <= self.against_votes || !self.meets_quorum(self.quorum_votes)? {
+        } else if self.for_votes <= self.against_votes
+            || !self.meets_approval_ratio(self.approval_ratio_bps)?
+            || !self.meets_quorum(self.quorum_votes)?
+            || !self.meets_min_participation()?
+        {
             return Some(ProposalState::Defeated);
         } else if self.queued_at > 0 {
             return Some(ProposalState::Queued);
@@ -161,6 +210,52 @@ impl Proposal {
         Some(ProposalState::Succeeded)
     }
 
+    /// The time at which the outcome of the vote is final.
+    ///
+    /// Votes may no longer change after [Proposal::voting_ends_at], so this is the same timestamp.
+    /// This is intentionally derived rather than stored as a field on the [Proposal],
+    /// so it can never disagree with [Proposal::voting_ends_at].
+    pub fn finalized_at(&self) -> i64 {
+        self.voting_ends_at
+    }
+
+    /// The earliest time at which a succeeded proposal may be queued, given a
+    /// [GovernanceParameters::post_finalize_cooloff_seconds].
+    pub fn queueable_at(&self, post_finalize_cooloff_seconds: u64) -> Option<i64> {
+        self.finalized_at()
+            .checked_add(post_finalize_cooloff_seconds.to_i64()?)
+    }
+
+    /// Asserts that the proposal has succeeded and that the
+    /// [GovernanceParameters::post_finalize_cooloff_seconds] have elapsed, so it may be queued.
+    pub fn assert_queueable(&self, now: i64, post_finalize_cooloff_seconds: u64) -> Result<()> {
+        let proposal_state = unwrap_opt!(self.state(now), "invalid state");
+        if proposal_state != ProposalState::Succeeded {
+            msg!("now: {}, voting_ends_at: {}", now, self.voting_ends_at);
+            msg!(
+                "for votes: {}, against votes: {}",
+                self.for_votes,
+                self.against_votes,
+            );
+            msg!(
+                "quorum req: {}, abstain votes: {}",
+                self.quorum_votes,
+                self.abstain_votes,
+            );
+            invariant!(
+                proposal_state == ProposalState::Succeeded,
+                "proposal must be succeeded to be queued"
+            );
+        }
+
+        let queueable_at = unwrap_int!(self.queueable_at(post_finalize_cooloff_seconds));
+        if now < queueable_at {
+            msg!("now: {}, queueable_at: {}", now, queueable_at);
+            invariant!(now >= queueable_at, PostFinalizeCooloffNotElapsed);
+        }
+        Ok(())
+    }
+
     /// Converts this proposal to Smart Wallet [smart_wallet::TXInstruction]s.
     pub fn to_smart_wallet_instructions(&self) -> Vec<smart_wallet::TXInstruction> {
         self.instructions
@@ -241,6 +336,7 @@ impl Proposal {
 mod tests {
     use super::*;
     use proptest::prelude::*;
+    use vipers::IntoCmpError;
 
     /// Maximum seconds elapsed between two checkpoints.
     /// [i32::MAX] corresponds to about 70 years.
@@ -279,6 +375,8 @@ mod tests {
         pub against_votes: u64,
         pub for_votes: u64,
         pub quorum_votes: u64,
+        pub approval_ratio_bps: u64,
+        pub min_participation_votes: u64,
     }
 
     fn test_proposal_state(t: TestProposalParams) -> ProposalState {
@@ -292,6 +390,8 @@ mod tests {
             voting_ends_at: t.voting_ends_at,
             queued_at: t.queued_at,
             quorum_votes: t.quorum_votes,
+            approval_ratio_bps: t.approval_ratio_bps,
+            min_participation_votes: t.min_participation_votes,
             ..Proposal::default()
         };
 
@@ -430,4 +530,234 @@ mod tests {
             assert_eq!(test_proposal_state(params), ProposalState::Succeeded);
         }
     }
+
+    #[test]
+    fn test_queueable_at_without_cooloff() {
+        let proposal = Proposal {
+            activated_at: 1,
+            voting_ends_at: 1_000,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.queueable_at(0).unwrap(), proposal.voting_ends_at);
+    }
+
+    #[test]
+    fn test_queueable_at_with_cooloff() {
+        let cooloff: u64 = 3_600;
+        let proposal = Proposal {
+            activated_at: 1,
+            voting_ends_at: 1_000,
+            for_votes: 10,
+            quorum_votes: 1,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.queueable_at(cooloff).unwrap(), 4_600);
+
+        // succeeded, but the cooloff has not elapsed yet
+        assert_eq!(proposal.state(1_000).unwrap(), ProposalState::Succeeded);
+        assert_eq!(
+            proposal.assert_queueable(1_000, cooloff).into_cmp_error(),
+            error!(crate::ErrorCode::PostFinalizeCooloffNotElapsed).into_cmp_error()
+        );
+        assert_eq!(
+            proposal.assert_queueable(4_599, cooloff).into_cmp_error(),
+            error!(crate::ErrorCode::PostFinalizeCooloffNotElapsed).into_cmp_error()
+        );
+
+        // cooloff has elapsed
+        assert!(proposal.assert_queueable(4_600, cooloff).is_ok());
+        assert!(proposal.assert_queueable(10_000, cooloff).is_ok());
+
+        // without a cooloff, it may be queued as soon as voting ends
+        assert!(proposal.assert_queueable(1_000, 0).is_ok());
+
+        // still voting
+        assert!(proposal.assert_queueable(999, 0).is_err());
+    }
+
+    #[test]
+    fn test_assert_queueable_defeated() {
+        let proposal = Proposal {
+            activated_at: 1,
+            voting_ends_at: 1_000,
+            for_votes: 10,
+            against_votes: 10,
+            quorum_votes: 1,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.state(10_000).unwrap(), ProposalState::Defeated);
+        assert!(proposal.assert_queueable(10_000, 0).is_err());
+    }
+
+    #[test]
+    fn test_queueable_at_overflow() {
+        let proposal = Proposal {
+            voting_ends_at: i64::MAX,
+            ..Proposal::default()
+        };
+        assert!(proposal.queueable_at(1).is_none());
+        assert!(proposal.queueable_at(u64::MAX).is_none());
+    }
+
+    fn finished_proposal_state(
+        for_votes: u64,
+        against_votes: u64,
+        approval_ratio_bps: u64,
+    ) -> ProposalState {
+        test_proposal_state(TestProposalParams {
+            activated_at: 1,
+            voting_ends_at: 1_000,
+            current_ts: 1_000,
+            for_votes,
+            against_votes,
+            approval_ratio_bps,
+            ..TestProposalParams::default()
+        })
+    }
+
+    #[test]
+    fn test_approval_ratio_supermajority_boundary() {
+        // exactly 66.67%
+        assert!(Proposal {
+            for_votes: 6_667,
+            against_votes: 3_333,
+            ..Proposal::default()
+        }
+        .meets_approval_ratio(6_667)
+        .unwrap());
+        assert_eq!(
+            finished_proposal_state(6_667, 3_333, 6_667),
+            ProposalState::Succeeded
+        );
+
+        // one vote short of 66.67%
+        assert_eq!(
+            finished_proposal_state(6_666, 3_334, 6_667),
+            ProposalState::Defeated
+        );
+
+        // two thirds is just below 6667 bps
+        assert_eq!(
+            finished_proposal_state(2, 1, 6_667),
+            ProposalState::Defeated
+        );
+        assert_eq!(
+            finished_proposal_state(2, 1, 6_666),
+            ProposalState::Succeeded
+        );
+    }
+
+    #[test]
+    fn test_approval_ratio_simple_majority() {
+        assert_eq!(
+            finished_proposal_state(5_001, 4_999, 5_001),
+            ProposalState::Succeeded
+        );
+        assert_eq!(
+            finished_proposal_state(5_000, 5_000, 5_001),
+            ProposalState::Defeated
+        );
+        // a ratio of zero only requires more votes for than against
+        assert_eq!(finished_proposal_state(2, 1, 0), ProposalState::Succeeded);
+        assert_eq!(finished_proposal_state(1, 1, 0), ProposalState::Defeated);
+    }
+
+    #[test]
+    fn test_approval_ratio_large_tally() {
+        // 50.008% for: a simple majority, but short of 50.01%
+        assert_eq!(
+            finished_proposal_state(50_004, 49_996, 0),
+            ProposalState::Succeeded
+        );
+        assert_eq!(
+            finished_proposal_state(50_004, 49_996, 5_000),
+            ProposalState::Succeeded
+        );
+        assert_eq!(
+            finished_proposal_state(50_004, 49_996, 5_001),
+            ProposalState::Defeated
+        );
+        assert_eq!(
+            finished_proposal_state(50_010, 49_990, 5_001),
+            ProposalState::Succeeded
+        );
+    }
+
+    #[test]
+    fn test_approval_ratio_no_overflow() {
+        let proposal = Proposal {
+            for_votes: u64::MAX,
+            against_votes: u64::MAX / 2,
+            ..Proposal::default()
+        };
+        assert!(proposal.meets_approval_ratio(6_666).unwrap());
+        assert!(!proposal.meets_approval_ratio(6_667).unwrap());
+        assert!(proposal.meets_approval_ratio(MAX_BPS).is_some());
+    }
+
+    #[test]
+    fn test_meets_quorum_but_not_min_participation() {
+        let params = GovernanceParameters {
+            quorum_votes: 100,
+            min_participation_bps: 1_000,
+            ..GovernanceParameters::default()
+        };
+        // 10% of 10_000 total voting power
+        let min_participation_votes = params.min_participation_votes(10_000).unwrap();
+        assert_eq!(min_participation_votes, 1_000);
+
+        let finished = |for_votes, against_votes, abstain_votes| {
+            test_proposal_state(TestProposalParams {
+                activated_at: 1,
+                voting_ends_at: 1_000,
+                current_ts: 1_000,
+                for_votes,
+                against_votes,
+                abstain_votes,
+                quorum_votes: params.quorum_votes,
+                min_participation_votes,
+                ..TestProposalParams::default()
+            })
+        };
+
+        // meets quorum, unanimous, but not enough of the supply voted
+        assert_eq!(finished(999, 0, 0), ProposalState::Defeated);
+        // abstain and against votes count towards participation
+        assert_eq!(finished(600, 200, 200), ProposalState::Succeeded);
+        assert_eq!(finished(600, 200, 199), ProposalState::Defeated);
+    }
+
+    #[test]
+    fn test_vote_weight_fixed_at_first_cast() {
+        let mut proposal = Proposal::default();
+        let mut vote = Vote::default();
+
+        // first cast records the weight
+        let weight = proposal
+            .record_vote(&mut vote, VoteSide::For.into(), 100)
+            .unwrap();
+        assert_eq!(weight, 100);
+        assert_eq!(vote.weight, 100);
+        assert_eq!(proposal.for_votes, 100);
+
+        // time passes and the voting power decays; changing the vote keeps the first-cast weight
+        let weight = proposal
+            .record_vote(&mut vote, VoteSide::Against.into(), 80)
+            .unwrap();
+        assert_eq!(weight, 100);
+        assert_eq!(proposal.for_votes, 0);
+        assert_eq!(proposal.against_votes, 100);
+
+        // rescinding and voting again also keeps it
+        proposal
+            .record_vote(&mut vote, VoteSide::Pending.into(), 60)
+            .unwrap();
+        assert_eq!(proposal.against_votes, 0);
+        assert_eq!(vote.weight, 100);
+        proposal
+            .record_vote(&mut vote, VoteSide::Abstain.into(), 40)
+            .unwrap();
+        assert_eq!(proposal.abstain_votes, 100);
+        assert_eq!(proposal.for_votes + proposal.against_votes, 0);
+    }
 }
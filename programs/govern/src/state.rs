@@ -1,6 +1,7 @@
 //! Struct definitions for accounts that hold state.
 
 use anchor_lang::prelude::*;
+use vipers::{invariant, unwrap_int};
 
 /// A Governor is the "DAO": it is the account that holds control over important protocol functions,
 /// including treasury, protocol parameters, and more.
@@ -14,6 +15,11 @@ pub struct Governor {
 
     /// The total number of [Proposal]s
     pub proposal_count: u64,
+    /// The number of [Proposal]s that have not been canceled.
+    ///
+    /// Unlike [Governor::proposal_count], which is also used to derive [Proposal] indices
+    /// and therefore never decreases, this is decremented whenever a [Proposal] is canceled.
+    pub effective_proposal_count: u64,
     /// The voting body associated with the Governor.
     /// This account is responsible for handling vote proceedings, such as:
     /// - activating proposals
@@ -27,6 +33,58 @@ pub struct Governor {
     pub params: GovernanceParameters,
 }
 
+impl Governor {
+    /// Asserts that `governor` is the address derived from [Governor::base] and [Governor::bump].
+    ///
+    /// The stored bump is used directly, avoiding a bump search on every call.
+    pub(crate) fn assert_pda(&self, governor: &Pubkey) -> Result<()> {
+        let expected_governor = Pubkey::create_program_address(
+            &[
+                b"MeteoraGovernor".as_ref(),
+                self.base.as_ref(),
+                &[self.bump],
+            ],
+            &crate::ID,
+        );
+        let is_derived = expected_governor == Ok(*governor);
+        if !is_derived {
+            msg!(
+                "Governor {} is not derived from base {} and bump {}",
+                governor,
+                self.base,
+                self.bump
+            );
+            invariant!(is_derived, GovernorAddressMismatch);
+        }
+        Ok(())
+    }
+
+    /// Records the creation of a [Proposal], returning the index assigned to it.
+    pub(crate) fn record_proposal_created(&mut self) -> Result<u64> {
+        let index = self.proposal_count;
+        self.proposal_count = unwrap_int!(self.proposal_count.checked_add(1));
+        self.effective_proposal_count = unwrap_int!(self.effective_proposal_count.checked_add(1));
+        Ok(index)
+    }
+
+    /// Records the cancellation of a [Proposal].
+    pub(crate) fn record_proposal_canceled(&mut self) -> Result<()> {
+        self.effective_proposal_count = unwrap_int!(self.effective_proposal_count.checked_sub(1));
+        Ok(())
+    }
+}
+
+/// The number of basis points in 100%.
+pub const MAX_BPS: u64 = 10_000;
+
+/// Derives the address and bump seed of the [Governor] with the given base.
+///
+/// This searches for the bump, so it is meant for off-chain and test use;
+/// on-chain checks use the stored [Governor::bump] instead.
+pub fn governor_pda(base: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"MeteoraGovernor".as_ref(), base.as_ref()], &crate::ID)
+}
+
 /// Governance parameters.
 #[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub struct GovernanceParameters {
@@ -38,6 +96,53 @@ pub struct GovernanceParameters {
     pub quorum_votes: u64,
     /// The timelock delay of the DAO's created proposals.
     pub timelock_delay_seconds: i64,
+    /// The minimum time between a proposal's voting ending as succeeded and it becoming queueable, in seconds.
+    /// This gives the community time to react to the outcome. Zero disables the cooloff.
+    pub post_finalize_cooloff_seconds: u64,
+    /// The minimum share of votes in favor, out of the votes in favor and against,
+    /// required for a proposal to succeed, in basis points. For example, 6667 requires a two-thirds supermajority.
+    /// Zero only requires more votes for than against, as before this parameter existed.
+    /// Any non-zero ratio is checked in addition to that: 5001 requires at least 50.01% of the votes,
+    /// which is stricter than a simple majority once more than 10000 votes are cast.
+    /// Must not exceed [MAX_BPS].
+    pub approval_ratio_bps: u64,
+    /// The minimum share of the total voting power that must vote (for, against or abstain)
+    /// for a proposal to succeed, in basis points. Zero disables the requirement. Must not exceed [MAX_BPS].
+    ///
+    /// This complements [GovernanceParameters::quorum_votes]: both are compared against the sum of all votes,
+    /// but the quorum is a fixed number of votes whereas this scales with the total voting power
+    /// when the proposal is activated. A proposal must meet both, so the stricter one applies.
+    pub min_participation_bps: u64,
+}
+
+impl GovernanceParameters {
+    /// Asserts that the parameters are within their allowed ranges.
+    pub(crate) fn validate(&self) -> Result<()> {
+        invariant!(
+            self.timelock_delay_seconds >= 0,
+            "timelock delay must be at least 0 seconds"
+        );
+        invariant!(
+            self.approval_ratio_bps <= MAX_BPS,
+            "approval ratio must be at most 10000 bps"
+        );
+        invariant!(
+            self.min_participation_bps <= MAX_BPS,
+            "min participation must be at most 10000 bps"
+        );
+        Ok(())
+    }
+
+    /// Computes the number of votes needed to meet [GovernanceParameters::min_participation_bps]
+    /// given the total voting power. Rounds up, so any non-zero requirement needs at least one vote.
+    pub fn min_participation_votes(&self, total_voting_power: u64) -> Option<u64> {
+        u128::from(total_voting_power)
+            .checked_mul(self.min_participation_bps.into())?
+            .checked_add(u128::from(MAX_BPS).checked_sub(1)?)?
+            .checked_div(MAX_BPS.into())?
+            .try_into()
+            .ok()
+    }
 }
 
 /// A Proposal is a pending transaction that may or may not be executed by the DAO.
@@ -56,6 +161,11 @@ pub struct Proposal {
 
     /// The number of votes in support of a proposal required in order for a quorum to be reached and for a vote to succeed
     pub quorum_votes: u64,
+    /// The [GovernanceParameters::approval_ratio_bps] at the time the proposal was created.
+    pub approval_ratio_bps: u64,
+    /// The number of votes required to meet the [GovernanceParameters::min_participation_bps],
+    /// computed from the total voting power at the time the proposal was activated.
+    pub min_participation_votes: u64,
     /// Current number of votes in favor of this proposal
     pub for_votes: u64,
     /// Current number of votes in opposition to this proposal
@@ -120,6 +230,7 @@ pub struct Vote {
     /// The side of the vote taken.
     pub side: u8,
     /// The number of votes this vote holds.
+    /// This is fixed when the vote is first cast and does not change with later votes on the same [Proposal].
     pub weight: u64,
 }
 
@@ -156,12 +267,35 @@ pub struct ProposalAccountMeta {
     pub is_writable: bool,
 }
 
+impl From<&ProposalAccountMeta> for AccountMeta {
+    fn from(
+        &ProposalAccountMeta {
+            pubkey,
+            is_signer,
+            is_writable,
+        }: &ProposalAccountMeta,
+    ) -> AccountMeta {
+        AccountMeta {
+            pubkey,
+            is_signer,
+            is_writable,
+        }
+    }
+}
+
 #[cfg(test)]
 mod state_test {
     use std::assert_eq;
 
-    use crate::{Proposal, ProposalAccountMeta, ProposalInstruction};
-    use anchor_lang::{prelude::Pubkey, AnchorSerialize, Discriminator};
+    use crate::{
+        governor_pda, ErrorCode, GovernanceParameters, Governor, Proposal, ProposalAccountMeta,
+        ProposalInstruction, MAX_BPS,
+    };
+    use anchor_lang::{
+        prelude::{AccountMeta, Pubkey},
+        AnchorSerialize, Discriminator,
+    };
+    use vipers::IntoCmpError;
 
     #[test]
     fn test_proposal_instruction_space() {
@@ -248,4 +382,128 @@ mod state_test {
         assert_eq!(extra_bytes, 31);
         assert_eq!(bytes_length <= proposal_rental_space, true);
     }
+
+    #[test]
+    fn test_proposal_account_meta_to_account_meta() {
+        let pubkey = Pubkey::new_unique();
+        for (is_signer, is_writable) in [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let meta = ProposalAccountMeta {
+                pubkey,
+                is_signer,
+                is_writable,
+            };
+            let account_meta: AccountMeta = (&meta).into();
+
+            assert_eq!(account_meta.pubkey, pubkey);
+            assert_eq!(account_meta.is_signer, is_signer);
+            assert_eq!(account_meta.is_writable, is_writable);
+        }
+    }
+
+    #[test]
+    fn test_governor_effective_proposal_count() {
+        let mut governor = Governor::default();
+
+        for expected_index in 0..3 {
+            let index = governor.record_proposal_created().unwrap();
+            assert_eq!(index, expected_index);
+        }
+        assert_eq!(governor.proposal_count, 3);
+        assert_eq!(governor.effective_proposal_count, 3);
+
+        governor.record_proposal_canceled().unwrap();
+        assert_eq!(governor.proposal_count, 3);
+        assert_eq!(governor.effective_proposal_count, 2);
+
+        // Indices keep increasing even though a proposal was canceled.
+        let index = governor.record_proposal_created().unwrap();
+        assert_eq!(index, 3);
+        assert_eq!(governor.proposal_count, 4);
+        assert_eq!(governor.effective_proposal_count, 3);
+    }
+
+    #[test]
+    fn test_governor_pda() {
+        let base = Pubkey::new_unique();
+        let (governor, bump) = governor_pda(&base);
+
+        assert_eq!(
+            Pubkey::create_program_address(
+                &[b"MeteoraGovernor".as_ref(), base.as_ref(), &[bump]],
+                &crate::ID
+            )
+            .unwrap(),
+            governor
+        );
+
+        let governor_state = Governor {
+            base,
+            bump,
+            ..Governor::default()
+        };
+        assert!(governor_state.assert_pda(&governor).is_ok());
+    }
+
+    #[test]
+    fn test_governor_pda_substituted() {
+        let base = Pubkey::new_unique();
+        let (governor, bump) = governor_pda(&base);
+        let governor_state = Governor {
+            base,
+            bump,
+            ..Governor::default()
+        };
+
+        // a governor derived from another base
+        let (other_governor, _) = governor_pda(&Pubkey::new_unique());
+        assert_eq!(
+            governor_state.assert_pda(&other_governor).into_cmp_error(),
+            anchor_lang::error!(ErrorCode::GovernorAddressMismatch).into_cmp_error()
+        );
+
+        // a governor state with a non-canonical bump
+        let non_canonical = Governor {
+            bump: bump.wrapping_sub(1),
+            ..governor_state
+        };
+        assert_eq!(
+            non_canonical.assert_pda(&governor).into_cmp_error(),
+            anchor_lang::error!(ErrorCode::GovernorAddressMismatch).into_cmp_error()
+        );
+    }
+
+    #[test]
+    fn test_governance_parameters_validate() {
+        let params = GovernanceParameters {
+            approval_ratio_bps: MAX_BPS,
+            ..GovernanceParameters::default()
+        };
+        assert!(params.validate().is_ok());
+
+        let params = GovernanceParameters {
+            approval_ratio_bps: MAX_BPS + 1,
+            ..GovernanceParameters::default()
+        };
+        assert!(params.validate().is_err());
+    }
+
+    #[test]
+    fn test_min_participation_votes() {
+        let mut params = GovernanceParameters::default();
+        assert_eq!(params.min_participation_votes(1_000_000).unwrap(), 0);
+
+        params.min_participation_bps = 2_500;
+        assert_eq!(params.min_participation_votes(1_000_000).unwrap(), 250_000);
+        // rounds up
+        assert_eq!(params.min_participation_votes(1_001).unwrap(), 251);
+        assert_eq!(params.min_participation_votes(1).unwrap(), 1);
+        assert_eq!(params.min_participation_votes(0).unwrap(), 0);
+
+        params.min_participation_bps = MAX_BPS;
+        assert_eq!(params.min_participation_votes(u64::MAX).unwrap(), u64::MAX);
+
+        params.min_participation_bps = MAX_BPS + 1;
+        assert!(params.validate().is_err());
+    }
 }
@@ -30,6 +30,7 @@ impl<'info> ExecuteTransaction<'info> {
         remaining_accounts: &[AccountInfo<'info>],
     ) -> Result<()> {
         for ix in self.transaction.instructions.iter() {
+            ix.assert_accounts_present(remaining_accounts)?;
             solana_program::program::invoke_signed(&(ix).into(), remaining_accounts, seeds)?;
         }
 
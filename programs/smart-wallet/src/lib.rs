@@ -240,4 +240,6 @@ pub enum ErrorCode {
     SubaccountOwnerMismatch,
     #[msg("Number of signers is not zero.")]
     NumSignerIsNotZero,
+    #[msg("The program or an account referenced by the transaction instruction is missing.")]
+    MissingInstructionAccount,
 }
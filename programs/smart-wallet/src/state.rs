@@ -3,7 +3,7 @@
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
-use vipers::unwrap_or_err;
+use vipers::{invariant, unwrap_or_err};
 
 /// A [SmartWallet] is a multisig wallet with Timelock capabilities.
 #[account]
@@ -127,6 +127,26 @@ impl TXInstruction {
             + (self.keys.len() as usize) * std::mem::size_of::<TXAccountMeta>()
             + (self.data.len() as usize)
     }
+
+    /// Asserts that the program and every account referenced by this instruction
+    /// are present in `account_infos`.
+    pub fn assert_accounts_present(&self, account_infos: &[AccountInfo]) -> crate::Result<()> {
+        let is_program_present = account_infos
+            .iter()
+            .any(|info| info.key() == self.program_id);
+        if !is_program_present {
+            msg!("Instruction program {} is missing", self.program_id);
+            invariant!(is_program_present, MissingInstructionAccount);
+        }
+        for meta in self.keys.iter() {
+            let is_present = account_infos.iter().any(|info| info.key() == meta.pubkey);
+            if !is_present {
+                msg!("Instruction account {} is missing", meta.pubkey);
+                invariant!(is_present, MissingInstructionAccount);
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Account metadata used to define [TXInstruction]s
@@ -220,9 +240,13 @@ pub struct StagedTXInstruction {
 
 #[cfg(test)]
 mod state_test {
-    use crate::SmartWallet;
-    use anchor_lang::{prelude::Pubkey, AnchorSerialize, Discriminator};
+    use crate::{ErrorCode, SmartWallet, TXAccountMeta, TXInstruction};
+    use anchor_lang::{
+        prelude::{AccountInfo, Pubkey},
+        AnchorSerialize, Discriminator,
+    };
     use std::assert_eq;
+    use vipers::IntoCmpError;
 
     #[test]
     fn test_smartwallet_space() {
@@ -282,4 +306,90 @@ mod state_test {
             assert_eq!(extra_bytes, 26);
         }
     }
+
+    #[test]
+    fn test_tx_instruction_missing_account() {
+        let owner = Pubkey::default();
+        let program_id = Pubkey::new_unique();
+        let present_key = Pubkey::new_unique();
+        let mut program_lamports = 0;
+        let mut program_data = vec![];
+        let mut lamports = 0;
+        let mut data = vec![];
+        let account_infos = [
+            AccountInfo::new(
+                &program_id,
+                false,
+                false,
+                &mut program_lamports,
+                &mut program_data,
+                &owner,
+                true,
+                0,
+            ),
+            AccountInfo::new(
+                &present_key,
+                false,
+                true,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                0,
+            ),
+        ];
+
+        let mut ix = TXInstruction {
+            program_id,
+            keys: vec![TXAccountMeta {
+                pubkey: present_key,
+                is_signer: false,
+                is_writable: true,
+            }],
+            data: vec![],
+        };
+        assert!(ix.assert_accounts_present(&account_infos).is_ok());
+
+        ix.keys.push(TXAccountMeta {
+            pubkey: Pubkey::new_unique(),
+            is_signer: false,
+            is_writable: false,
+        });
+        assert_eq!(
+            ix.assert_accounts_present(&account_infos).into_cmp_error(),
+            anchor_lang::error!(ErrorCode::MissingInstructionAccount).into_cmp_error()
+        );
+    }
+
+    #[test]
+    fn test_tx_instruction_missing_program() {
+        let owner = Pubkey::default();
+        let present_key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![];
+        let account_infos = [AccountInfo::new(
+            &present_key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        )];
+
+        let ix = TXInstruction {
+            program_id: Pubkey::new_unique(),
+            keys: vec![TXAccountMeta {
+                pubkey: present_key,
+                is_signer: false,
+                is_writable: true,
+            }],
+            data: vec![],
+        };
+        assert_eq!(
+            ix.assert_accounts_present(&account_infos).into_cmp_error(),
+            anchor_lang::error!(ErrorCode::MissingInstructionAccount).into_cmp_error()
+        );
+    }
 }
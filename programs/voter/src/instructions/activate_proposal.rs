@@ -10,6 +10,8 @@ pub struct ActivateProposal<'info> {
     /// The [Proposal].
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
+    /// The [Locker::token_mint], used to compute the total voting power.
+    pub token_mint: Account<'info, Mint>,
     /// The user's [Escrow].
     pub escrow: Account<'info, Escrow>,
     /// The [Escrow]'s owner.
@@ -22,6 +24,12 @@ impl<'info> ActivateProposal<'info> {
     /// Activates the proposal.
     pub fn activate_proposal(&mut self) -> Result<()> {
         let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        // the total voting power is only used for the minimum participation requirement.
+        let total_voting_power = if self.governor.params.min_participation_bps == 0 {
+            0
+        } else {
+            self.locker.max_voting_power(self.token_mint.supply)
+        };
 
         govern::cpi::activate_proposal(
             CpiContext::new(
@@ -29,6 +37,7 @@ impl<'info> ActivateProposal<'info> {
                 self.to_activate_proposal_accounts(),
             )
             .with_signer(seeds),
+            total_voting_power,
         )?;
 
         Ok(())
@@ -54,6 +63,7 @@ impl<'info> Validate<'info> for ActivateProposal<'info> {
         assert_keys_eq!(self.locker, self.governor.locker);
         assert_keys_eq!(self.governor, self.locker.governor);
         assert_keys_eq!(self.proposal.governor, self.governor);
+        assert_keys_eq!(self.token_mint, self.locker.token_mint);
         assert_keys_eq!(self.escrow.locker, self.locker);
         assert_keys_eq!(self.escrow.owner, self.escrow_owner);
 
@@ -10,6 +10,8 @@ pub struct ActivateProposalInitialPhase<'info> {
     /// The [Proposal].
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
+    /// The [Locker::token_mint], used to compute the total voting power.
+    pub token_mint: Account<'info, Mint>,
     /// The [govern] program.
     pub govern_program: Program<'info, govern::program::Govern>,
     /// The smart wallet on the [Governor].
@@ -20,6 +22,12 @@ impl<'info> ActivateProposalInitialPhase<'info> {
     /// Activates the proposal.
     pub fn activate_proposal(&mut self) -> Result<()> {
         let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        // the total voting power is only used for the minimum participation requirement.
+        let total_voting_power = if self.governor.params.min_participation_bps == 0 {
+            0
+        } else {
+            self.locker.max_voting_power(self.token_mint.supply)
+        };
 
         govern::cpi::activate_proposal(
             CpiContext::new(
@@ -27,6 +35,7 @@ impl<'info> ActivateProposalInitialPhase<'info> {
                 self.to_activate_proposal_accounts(),
             )
             .with_signer(seeds),
+            total_voting_power,
         )?;
 
         Ok(())
@@ -47,6 +56,7 @@ impl<'info> Validate<'info> for ActivateProposalInitialPhase<'info> {
         assert_keys_eq!(self.locker, self.governor.locker);
         assert_keys_eq!(self.governor, self.locker.governor);
         assert_keys_eq!(self.proposal.governor, self.governor);
+        assert_keys_eq!(self.token_mint, self.locker.token_mint);
         assert_keys_eq!(self.smart_wallet, self.governor.smart_wallet);
         let phase = self.locker.get_current_phase()?;
 
@@ -21,8 +21,23 @@ pub struct NewEscrow<'info> {
     pub escrow: Account<'info, Escrow>,
 
     /// CHECK: Authority of the [Escrow] to be created.
+    /// Receives the rent of the [WithdrawRecord] when it is closed.
+    #[account(mut)]
     pub escrow_owner: UncheckedAccount<'info>,
 
+    /// CHECK: The [WithdrawRecord] of the [Escrow] owner, which may not exist yet.
+    /// It is closed to the [Escrow] owner once the relock cooldown has elapsed.
+    #[account(
+        mut,
+        seeds = [
+            b"WithdrawRecord".as_ref(),
+            locker.key().as_ref(),
+            escrow_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub withdraw_record: UncheckedAccount<'info>,
+
     /// Payer of the initialization.
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -49,6 +64,13 @@ impl<'info> NewEscrow<'info> {
         escrow.escrow_ends_at = 0;
         escrow.vote_delegate = self.escrow_owner.key();
 
+        // the relock cooldown has elapsed, so the record is no longer needed.
+        if !self.withdraw_record.data_is_empty() {
+            let withdraw_record: Account<WithdrawRecord> =
+                Account::try_from(&self.withdraw_record.to_account_info())?;
+            withdraw_record.close(self.escrow_owner.to_account_info())?;
+        }
+
         emit!(NewEscrowEvent {
             escrow: escrow.key(),
             escrow_owner: escrow.owner,
@@ -62,6 +84,22 @@ impl<'info> NewEscrow<'info> {
 
 impl<'info> Validate<'info> for NewEscrow<'info> {
     fn validate(&self) -> Result<()> {
+        // owners who have never withdrawn have no record
+        if self.withdraw_record.data_is_empty() {
+            return Ok(());
+        }
+
+        let withdraw_record: Account<WithdrawRecord> =
+            Account::try_from(&self.withdraw_record.to_account_info())?;
+        let relock_allowed_at = unwrap_int!(
+            withdraw_record.relock_allowed_at(self.locker.params.relock_cooldown_seconds)
+        );
+        let now = Clock::get()?.unix_timestamp;
+        if now < relock_allowed_at {
+            msg!("now: {}; relock_allowed_at: {}", now, relock_allowed_at);
+            invariant!(now >= relock_allowed_at, RelockCooldownNotElapsed);
+        }
+
         Ok(())
     }
 }
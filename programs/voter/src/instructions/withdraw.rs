@@ -25,12 +25,30 @@ pub struct Withdraw<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// CHECK: The [WithdrawRecord] of the [Escrow] owner.
+    /// It is only created when the [LockerParams::relock_cooldown_seconds] is non-zero,
+    /// so withdrawing from a [Locker] without a cooldown costs no rent.
+    /// The rent is refunded to the owner when [voter::new_escrow] closes the record.
+    #[account(
+        mut,
+        seeds = [
+            b"WithdrawRecord".as_ref(),
+            locker.key().as_ref(),
+            escrow_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub withdraw_record: UncheckedAccount<'info>,
+
     /// Token program.
     pub token_program: Program<'info, Token>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> Withdraw<'info> {
-    pub fn withdraw(&mut self) -> Result<()> {
+    pub fn withdraw(&mut self, withdraw_record_bump: u8) -> Result<()> {
         let seeds: &[&[&[u8]]] = escrow_seeds!(self.escrow);
 
         // transfer tokens from the escrow
@@ -54,16 +72,100 @@ impl<'info> Withdraw<'info> {
         let locker = &mut self.locker;
         locker.locked_supply = unwrap_int!(locker.locked_supply.checked_sub(self.escrow.amount));
 
+        let now = Clock::get()?.unix_timestamp;
+
         emit!(ExitEscrowEvent {
             escrow_owner: self.escrow.owner,
             locker: locker.key(),
             locker_supply: locker.locked_supply,
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: now,
             released_amount: self.escrow.amount,
         });
 
+        // remember the withdrawal for the relock cooldown
+        if self.locker.params.relock_cooldown_seconds > 0 {
+            self.create_withdraw_record(withdraw_record_bump, now)?;
+        }
+
         Ok(())
     }
+
+    /// Creates the [WithdrawRecord] of the [Escrow] owner.
+    fn create_withdraw_record(&self, bump: u8, now: i64) -> Result<()> {
+        let locker = self.locker.key();
+        let owner = self.escrow_owner.key();
+        let seeds: &[&[&[u8]]] = &[&[
+            b"WithdrawRecord".as_ref(),
+            locker.as_ref(),
+            owner.as_ref(),
+            &[bump],
+        ]];
+        let space = 8 + std::mem::size_of::<WithdrawRecord>();
+        let rent_lamports = Rent::get()?.minimum_balance(space);
+        let withdraw_record = self.withdraw_record.to_account_info();
+
+        // a record can only exist between a withdrawal and the next escrow,
+        // but anyone may send lamports to its address, which `create_account` rejects.
+        let current_lamports = withdraw_record.lamports();
+        if current_lamports == 0 {
+            anchor_lang::system_program::create_account(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::CreateAccount {
+                        from: self.payer.to_account_info(),
+                        to: withdraw_record.clone(),
+                    },
+                )
+                .with_signer(seeds),
+                rent_lamports,
+                space as u64,
+                &crate::ID,
+            )?;
+        } else {
+            let required_lamports = rent_lamports.saturating_sub(current_lamports);
+            if required_lamports > 0 {
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: self.payer.to_account_info(),
+                            to: withdraw_record.clone(),
+                        },
+                    ),
+                    required_lamports,
+                )?;
+            }
+            anchor_lang::system_program::allocate(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::Allocate {
+                        account_to_allocate: withdraw_record.clone(),
+                    },
+                )
+                .with_signer(seeds),
+                space as u64,
+            )?;
+            anchor_lang::system_program::assign(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::Assign {
+                        account_to_assign: withdraw_record.clone(),
+                    },
+                )
+                .with_signer(seeds),
+                &crate::ID,
+            )?;
+        }
+
+        let record = WithdrawRecord {
+            locker,
+            owner,
+            bump,
+            last_withdrawn_at: now,
+        };
+        let mut data = withdraw_record.try_borrow_mut_data()?;
+        record.try_serialize(&mut &mut data[..])
+    }
 }
 
 impl<'info> Validate<'info> for Withdraw<'info> {
@@ -76,7 +76,7 @@ pub mod voter {
     /// Exits the DAO; i.e., withdraws all staked tokens in an [Escrow] if the [Escrow] is unlocked.
     #[access_control(ctx.accounts.validate())]
     pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
-        ctx.accounts.withdraw()
+        ctx.accounts.withdraw(unwrap_bump!(ctx, "withdraw_record"))
     }
 
     /// Activates a proposal in token launch phase
@@ -131,4 +131,6 @@ pub enum ErrorCode {
     ExpirationIsNotZero,
     #[msg("Amount is zero")]
     AmountIsZero,
+    #[msg("Relock cooldown has not elapsed since the last withdrawal")]
+    RelockCooldownNotElapsed,
 }
@@ -27,6 +27,12 @@ impl Locker {
         }
         Ok(Phase::TokenLaunchPhase)
     }
+    /// Calculates the voting power of the given token supply if it were all locked up for the maximum duration.
+    /// Saturates at [u64::MAX] rather than failing, since no vote tally can exceed it.
+    pub fn max_voting_power(&self, token_supply: u64) -> u64 {
+        token_supply.saturating_mul(self.params.max_stake_vote_multiplier.into())
+    }
+
     /// Calculates the amount of voting power an [Escrow] has.
     pub fn calculate_voter_power(&self, escrow: &Escrow, now: i64) -> Option<u64> {
         // invalid `now` argument, should never happen.
@@ -48,6 +48,9 @@ pub struct LockerParams {
     pub max_stake_duration: u64,
     /// Minimum number of votes required to activate a proposal.
     pub proposal_activation_min_votes: u64,
+    /// Number of seconds an owner must wait after a withdrawal before creating a new [Escrow].
+    /// Zero disables the cooldown.
+    pub relock_cooldown_seconds: u64,
 }
 
 /// Locks tokens on behalf of a user.
@@ -112,6 +115,32 @@ impl Escrow {
     }
 }
 
+/// Tracks the last withdrawal of an [Escrow] owner from a [Locker].
+///
+/// Created by [voter::withdraw] when [LockerParams::relock_cooldown_seconds] is non-zero,
+/// as the [Escrow] itself is closed on withdrawal. [voter::new_escrow] reads it to enforce
+/// the cooldown, then closes it to refund its rent to the owner once the cooldown has elapsed.
+#[account]
+#[derive(Copy, Debug, Default)]
+pub struct WithdrawRecord {
+    /// The [Locker] that was withdrawn from.
+    pub locker: Pubkey,
+    /// The owner of the withdrawn [Escrow].
+    pub owner: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// When the [WithdrawRecord::owner] last withdrew from the [Locker].
+    pub last_withdrawn_at: i64,
+}
+
+impl WithdrawRecord {
+    /// Gets the earliest time at which the [WithdrawRecord::owner] may create a new [Escrow].
+    pub fn relock_allowed_at(&self, relock_cooldown_seconds: u64) -> Option<i64> {
+        self.last_withdrawn_at
+            .checked_add(relock_cooldown_seconds.try_into().ok()?)
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -311,4 +340,38 @@ mod tests {
         assert_eq!(locker.locked_supply, 0);
         assert_escrow(&locker, bob, current_ts, 0.0);
     }
+
+    #[test]
+    fn test_max_voting_power_saturates() {
+        let locker = Locker {
+            params: LockerParams {
+                max_stake_vote_multiplier: 20,
+                ..LockerParams::default()
+            },
+            ..Locker::default()
+        };
+
+        let max_supply = u64::MAX / 20;
+        assert_eq!(locker.max_voting_power(max_supply), max_supply * 20);
+        assert_eq!(locker.max_voting_power(max_supply + 1), u64::MAX);
+
+        // 1e9 tokens with 9 decimals
+        assert_eq!(locker.max_voting_power(1_000_000_000_000_000_000), u64::MAX);
+    }
+
+    #[test]
+    fn test_relock_allowed_at() {
+        let record = WithdrawRecord {
+            last_withdrawn_at: CANONICAL_START_TIME,
+            ..WithdrawRecord::default()
+        };
+
+        assert_eq!(record.relock_allowed_at(0).unwrap(), CANONICAL_START_TIME);
+        assert_eq!(
+            record.relock_allowed_at(WEEK as u64).unwrap(),
+            CANONICAL_START_TIME + WEEK
+        );
+        assert!(record.relock_allowed_at(u64::MAX).is_none());
+        assert!(record.relock_allowed_at(i64::MAX as u64).is_none());
+    }
 }
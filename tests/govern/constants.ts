@@ -36,4 +36,7 @@ export const DEFAULT_GOVERNANCE_PARAMETERS = {
     quorumVotes: DEFAULT_QUORUM_VOTES,
     votingDelay: DEFAULT_VOTE_DELAY,
     votingPeriod: DEFAULT_VOTE_PERIOD,
+    postFinalizeCooloffSeconds: new BN(0),
+    approvalRatioBps: new BN(0),
+    minParticipationBps: new BN(0),
 };
\ No newline at end of file
@@ -93,6 +93,7 @@ describe("Govern", () => {
   it("Governor was initialized", async () => {
     expect(governorState.bump).to.equal(bump);
     expect(governorState.proposalCount.toString()).to.eq("0");
+    expect(governorState.effectiveProposalCount.toString()).to.eq("0");
     expect(governorState.params.votingDelay.toString()).eq(
       DEFAULT_VOTE_DELAY.toString()
     );
@@ -148,6 +149,9 @@ describe("Govern", () => {
       expect(proposalData.quorumVotes.toString()).to.equal(
         governorState.params.quorumVotes.toString()
       );
+      expect(proposalData.approvalRatioBps.toString()).to.equal(
+        governorState.params.approvalRatioBps.toString()
+      );
       expect(proposalData.queuedTransaction.toString()).to.eq(
         anchor.web3.PublicKey.default.toString()
       );
@@ -158,6 +162,9 @@ describe("Govern", () => {
     });
 
     it("Cancel a proposal", async () => {
+      const beforeGovernorState = await program.account.governor.fetch(
+        governor
+      );
       await program.methods
         .cancelProposal()
         .accounts({
@@ -168,6 +175,111 @@ describe("Govern", () => {
         .rpc();
       const proposalData = await program.account.proposal.fetch(proposalKey);
       expect(proposalData.canceledAt.toNumber()).to.greaterThan(0);
+
+      const afterGovernorState = await program.account.governor.fetch(
+        governor
+      );
+      expect(afterGovernorState.proposalCount.toString()).to.eq(
+        beforeGovernorState.proposalCount.toString()
+      );
+      expect(afterGovernorState.effectiveProposalCount.toString()).to.eq(
+        beforeGovernorState.effectiveProposalCount.subn(1).toString()
+      );
+    });
+
+    it("Canceled proposal index is not reused", async () => {
+      await program.methods
+        .cancelProposal()
+        .accounts({
+          governor,
+          proposal: proposalKey,
+          proposer: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const governorData = await program.account.governor.fetch(governor);
+      expect(governorData.proposalCount.toString()).to.eq(
+        proposalIndex.addn(1).toString()
+      );
+      expect(governorData.effectiveProposalCount.lt(governorData.proposalCount))
+        .to.be.true;
+    });
+
+    it("Close votes only on proposals that can no longer be voted on", async () => {
+      // proposalKey is still a draft; create another proposal and cancel it
+      governorState = await program.account.governor.fetch(governor);
+      const [canceledProposalKey] =
+        await anchor.web3.PublicKey.findProgramAddress(
+          [
+            Buffer.from("MeteoraProposal"),
+            governor.toBuffer(),
+            governorState.proposalCount.toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        );
+      await program.methods
+        .createProposal(0, DUMMY_INSTRUCTIONS)
+        .accounts({
+          governor,
+          proposal: canceledProposalKey,
+          proposer: provider.wallet.publicKey,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .cancelProposal()
+        .accounts({
+          governor,
+          proposal: canceledProposalKey,
+          proposer: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const votes: Pubkey[] = [];
+      for (const proposal of [proposalKey, canceledProposalKey]) {
+        const [vote] = await anchor.web3.PublicKey.findProgramAddress(
+          [
+            Buffer.from("MeteoraVote"),
+            proposal.toBuffer(),
+            provider.wallet.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .newVote(provider.wallet.publicKey)
+          .accounts({
+            proposal,
+            vote,
+            payer: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        votes.push(vote);
+      }
+      const [draftVote, canceledVote] = votes;
+
+      await program.methods
+        .closeVotes()
+        .accounts({
+          voter: provider.wallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: draftVote, isSigner: false, isWritable: true },
+          { pubkey: proposalKey, isSigner: false, isWritable: false },
+          { pubkey: canceledVote, isSigner: false, isWritable: true },
+          { pubkey: canceledProposalKey, isSigner: false, isWritable: false },
+        ])
+        .rpc();
+
+      const draftVoteAccount = await provider.connection.getAccountInfo(
+        draftVote
+      );
+      const canceledVoteAccount = await provider.connection.getAccountInfo(
+        canceledVote
+      );
+      expect(draftVoteAccount).to.not.be.null;
+      expect(canceledVoteAccount).to.be.null;
     });
 
     context("Proposal meta", () => {
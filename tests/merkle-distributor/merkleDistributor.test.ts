@@ -24,6 +24,7 @@ import {
   deriveGovern,
   deriveLocker,
   deriveSmartWallet,
+  deriveWithdrawRecord,
   getOrCreateATA,
   invokeAndAssertError,
 } from "../utils";
@@ -92,6 +93,10 @@ describe("merkle-distributor", () => {
 
   async function setupEscrow(wallet: Wallet) {
     const [escrow, _eBump] = deriveEscrow(locker, wallet.publicKey);
+    const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+      locker,
+      wallet.publicKey
+    );
 
     const voterProgram = createVoterProgram(wallet, VOTER_PROGRAM_ID);
 
@@ -101,6 +106,7 @@ describe("merkle-distributor", () => {
         escrow,
         escrowOwner: wallet.publicKey,
         locker,
+        withdrawRecord,
         payer: wallet.publicKey,
         systemProgram: web3.SystemProgram.programId,
       })
@@ -95,6 +95,16 @@ export function deriveEscrow(
   );
 }
 
+export function deriveWithdrawRecord(
+  locker: web3.PublicKey,
+  escrowOwner: web3.PublicKey
+) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("WithdrawRecord"), locker.toBytes(), escrowOwner.toBytes()],
+    VOTER_PROGRAM_ID
+  );
+}
+
 export function deriveTransaction(smartWallet: web3.PublicKey, txNo: BN) {
   return web3.PublicKey.findProgramAddressSync(
     [
@@ -286,6 +296,9 @@ export async function createGovernor(
       votingPeriod,
       quorumVotes,
       timelockDelaySeconds,
+      postFinalizeCooloffSeconds: new BN(0),
+      approvalRatioBps: new BN(0),
+      minParticipationBps: new BN(0),
     })
     .accounts({
       base: baseKeypair.publicKey,
@@ -328,6 +341,7 @@ export async function createLocker(
       maxStakeVoteMultiplier,
       minStakeDuration,
       proposalActivationMinVotes,
+      relockCooldownSeconds: new BN(0),
     })
     .accounts({
       locker,
@@ -26,6 +26,7 @@ import {
   deriveSmartWallet,
   deriveTransaction,
   deriveVote,
+  deriveWithdrawRecord,
   getOnChainTime,
   getOrCreateATA,
   invokeAndAssertError,
@@ -76,6 +77,7 @@ describe("Locked voter", () => {
   const minStakeDuration: BN = new BN(10); // 10 seconds
   const maxStakeVoteMultiplier: number = 1;
   const proposalActivationMinVotes: BN = new BN(2); // min 2 vote to activate proposal
+  const relockCooldownSeconds: BN = new BN(0); // no relock cooldown
 
   async function createSetLockerParamsProposal() {
     const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
@@ -86,6 +88,7 @@ describe("Locked voter", () => {
         minStakeDuration,
         maxStakeDuration,
         proposalActivationMinVotes,
+        relockCooldownSeconds,
       },
     });
     const ix: IProposalInstruction = {
@@ -238,6 +241,7 @@ describe("Locked voter", () => {
         maxStakeVoteMultiplier,
         minStakeDuration,
         proposalActivationMinVotes,
+        relockCooldownSeconds,
       })
       .accounts({
         locker,
@@ -398,6 +402,7 @@ describe("Locked voter", () => {
             maxStakeDuration,
             minStakeDuration,
             proposalActivationMinVotes,
+            relockCooldownSeconds,
           })
           .accounts({
             governor: govern,
@@ -420,6 +425,7 @@ describe("Locked voter", () => {
         minStakeDuration,
         maxStakeDuration,
         proposalActivationMinVotes,
+        relockCooldownSeconds,
       },
     });
 
@@ -513,6 +519,10 @@ describe("Locked voter", () => {
       const wallet = new Wallet(keypair);
       const voterProgram = createVoterProgram(wallet, VOTER_PROGRAM_ID);
       const [escrow, _bump] = deriveEscrow(locker, wallet.publicKey);
+      const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+        locker,
+        wallet.publicKey
+      );
 
       await voterProgram.methods
         .newEscrow()
@@ -520,6 +530,7 @@ describe("Locked voter", () => {
           escrow,
           escrowOwner: wallet.publicKey,
           locker,
+          withdrawRecord,
           payer: wallet.publicKey,
           systemProgram: web3.SystemProgram.programId,
         })
@@ -604,6 +615,10 @@ describe("Locked voter", () => {
     const wallet = new Wallet(nonClaimerKeypair);
 
     const [escrow, _bump] = deriveEscrow(locker, wallet.publicKey);
+    const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+      locker,
+      wallet.publicKey
+    );
 
     const voterProgram = createVoterProgram(wallet, VOTER_PROGRAM_ID);
 
@@ -613,6 +628,7 @@ describe("Locked voter", () => {
         escrow,
         escrowOwner: voterProgram.provider.publicKey,
         locker,
+        withdrawRecord,
         payer: voterProgram.provider.publicKey,
         systemProgram: web3.SystemProgram.programId,
       })
@@ -657,6 +673,10 @@ describe("Locked voter", () => {
     const wallet = new Wallet(nonClaimerKeypair);
 
     const [escrow, _bump] = deriveEscrow(locker, wallet.publicKey);
+    const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+      locker,
+      wallet.publicKey
+    );
     const voterProgram = createVoterProgram(wallet, VOTER_PROGRAM_ID);
 
     const userATA = await getOrCreateATA(
@@ -683,8 +703,10 @@ describe("Locked voter", () => {
             escrowOwner: voterProgram.provider.publicKey,
             escrowTokens: escrowATA,
             locker,
+            withdrawRecord,
             payer: voterProgram.provider.publicKey,
             tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: web3.SystemProgram.programId,
           })
           .rpc();
       },
@@ -726,6 +748,7 @@ describe("Locked voter", () => {
           .accounts({
             governor: govern,
             governProgram: GOVERN_PROGRAM_ID,
+            tokenMint: rewardMint,
             locker,
             proposal,
             smartWallet: wallet.publicKey,
@@ -745,6 +768,7 @@ describe("Locked voter", () => {
           .accounts({
             governor: govern,
             governProgram: GOVERN_PROGRAM_ID,
+            tokenMint: rewardMint,
             escrow,
             escrowOwner: wallet.publicKey,
             locker,
@@ -798,6 +822,11 @@ describe("Locked voter", () => {
           isWritable: true,
           pubkey: proposal,
         },
+        {
+          isSigner: false,
+          isWritable: false,
+          pubkey: rewardMint,
+        },
         {
           isSigner: false,
           isWritable: false,
@@ -1001,6 +1030,118 @@ describe("Locked voter", () => {
     }
   });
 
+  it("queue proposal after voting ends", async () => {
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const smartWalletProgram = createSmartWalletProgram(
+      wallet,
+      SMART_WALLET_PROGRAM_ID
+    );
+
+    while (true) {
+      const [proposalState, onchainTimestamp] = await Promise.all([
+        governProgram.account.proposal.fetch(proposal),
+        getOnChainTime(provider.connection),
+      ]);
+      if (proposalState.votingEndsAt.toNumber() > onchainTimestamp) {
+        console.log(
+          `${
+            proposalState.votingEndsAt.toNumber() - onchainTimestamp
+          } seconds until voting ends`
+        );
+        await sleep(1000);
+      } else {
+        break;
+      }
+    }
+
+    const smartWalletState = await smartWalletProgram.account.smartWallet.fetch(
+      smartWallet
+    );
+    const [transaction, _txBump] = deriveTransaction(
+      smartWallet,
+      smartWalletState.numTransactions
+    );
+
+    await governProgram.methods
+      .queueProposal()
+      .accounts({
+        governor: govern,
+        proposal,
+        transaction,
+        smartWallet,
+        payer: governProgram.provider.publicKey,
+        smartWalletProgram: SMART_WALLET_PROGRAM_ID,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    expect(proposalState.queuedAt.toString()).not.equal("0");
+    expect(proposalState.queuedTransaction.toBase58()).to.be.equal(
+      transaction.toBase58()
+    );
+  });
+
+  it("execute proposal emits executed event once", async () => {
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+
+    const remainingAccounts = [
+      {
+        isSigner: false,
+        isWritable: false,
+        pubkey: VOTER_PROGRAM_ID,
+      },
+      ...proposalState.instructions.flatMap((ix) =>
+        ix.keys.map((key) => {
+          return {
+            pubkey: key.pubkey,
+            isWritable: key.isWritable,
+            isSigner: false, // Need to override to false as these are signed by the smart wallet
+          };
+        })
+      ),
+    ];
+
+    const executeProposal = () =>
+      governProgram.methods
+        .executeProposal()
+        .accounts({
+          governor: govern,
+          proposal,
+          smartWallet,
+          transaction: proposalState.queuedTransaction,
+          smartWalletProgram: SMART_WALLET_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc({ commitment: "confirmed" });
+
+    const signature = await executeProposal();
+
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+    });
+    const eventParser = new anchor.EventParser(
+      GOVERN_PROGRAM_ID,
+      new anchor.BorshCoder(governProgram.idl)
+    );
+    const events = [...eventParser.parseLogs(tx.meta.logMessages)].filter(
+      (event) => event.name == "ProposalExecutedEvent"
+    );
+
+    expect(events.length).to.be.equal(1);
+    const event = events[0].data;
+    expect(event.governor.toBase58()).to.be.equal(govern.toBase58());
+    expect(event.proposal.toBase58()).to.be.equal(proposal.toBase58());
+    expect(event.index.toString()).to.be.equal(proposalState.index.toString());
+    expect(event.instructionCount.toString()).to.be.equal(
+      proposalState.instructions.length.toString()
+    );
+
+    // the queued transaction can only be executed once
+    await invokeAndAssertError(executeProposal, "AlreadyExecuted", false);
+  });
+
   it("users able to exit when phase 1 expired", async () => {
     const keypairs = [...claimerKeypairs, nonClaimerKeypair];
 
@@ -1027,6 +1168,10 @@ describe("Locked voter", () => {
       }
 
       const [escrow, _eBump] = deriveEscrow(locker, userWallet.publicKey);
+      const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+        locker,
+        userWallet.publicKey
+      );
 
       const userATA = await getOrCreateATA(
         rewardMint,
@@ -1050,12 +1195,20 @@ describe("Locked voter", () => {
           escrowOwner: voterProgram.provider.publicKey,
           escrowTokens: escrowATA,
           locker,
+          withdrawRecord,
           payer: voterProgram.provider.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: web3.SystemProgram.programId,
         })
         .rpc();
 
       // Escrow account closed
+
+      // no withdraw record is created without a relock cooldown
+      const withdrawRecordAccount = await provider.connection.getAccountInfo(
+        withdrawRecord
+      );
+      expect(withdrawRecordAccount).to.be.null;
     }
   });
 });
@@ -20,6 +20,7 @@ import {
   deriveLocker,
   deriveSmartWallet,
   deriveVote,
+  deriveWithdrawRecord,
   getOnChainTime,
   getOrCreateATA,
   getOrCreateVote,
@@ -44,6 +45,7 @@ describe("Locked voter", () => {
 
   let rewardMint: web3.PublicKey;
   let userKeypairs: web3.Keypair[] = [];
+  let withdrawnKeypair: web3.Keypair;
 
   // Smart wallet config
   let smartWalletOwners: web3.PublicKey[] = [];
@@ -59,6 +61,7 @@ describe("Locked voter", () => {
   const minStakeDuration: BN = new BN(10); // 10 seconds
   const maxStakeVoteMultiplier: number = 1;
   const proposalActivationMinVotes: BN = new BN(2); // min 2 vote to activate proposal
+  const relockCooldownSeconds: BN = new BN(5); // 5 seconds relock cooldown after withdraw
 
   async function createSetLockerParamsProposal() {
     const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
@@ -69,6 +72,7 @@ describe("Locked voter", () => {
         minStakeDuration,
         maxStakeDuration,
         proposalActivationMinVotes,
+        relockCooldownSeconds,
       },
     });
     const ix: IProposalInstruction = {
@@ -115,6 +119,7 @@ describe("Locked voter", () => {
         maxStakeVoteMultiplier,
         minStakeDuration,
         proposalActivationMinVotes,
+        relockCooldownSeconds,
       })
       .accounts({
         locker,
@@ -219,6 +224,10 @@ describe("Locked voter", () => {
       const wallet = new Wallet(keypair);
       const voterProgram = createVoterProgram(wallet, VOTER_PROGRAM_ID);
       const [escrow, _bump] = deriveEscrow(locker, wallet.publicKey);
+      const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+        locker,
+        wallet.publicKey
+      );
 
       await voterProgram.methods
         .newEscrow()
@@ -226,6 +235,7 @@ describe("Locked voter", () => {
           escrow,
           escrowOwner: wallet.publicKey,
           locker,
+          withdrawRecord,
           payer: wallet.publicKey,
           systemProgram: web3.SystemProgram.programId,
         })
@@ -342,6 +352,7 @@ describe("Locked voter", () => {
   it("able to withdraw if doesn't extend lock duration", async () => {
     const newWallet = await createAndFundWallet(provider.connection);
     const userWallet = new Wallet(newWallet.keypair);
+    withdrawnKeypair = newWallet.keypair;
 
     const userATA = await getOrCreateATA(
       rewardMint,
@@ -361,6 +372,10 @@ describe("Locked voter", () => {
 
     const voterProgram = createVoterProgram(userWallet, VOTER_PROGRAM_ID);
     const [escrow, _bump] = deriveEscrow(locker, userWallet.publicKey);
+    const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+      locker,
+      userWallet.publicKey
+    );
 
     await voterProgram.methods
       .newEscrow()
@@ -368,6 +383,7 @@ describe("Locked voter", () => {
         escrow,
         escrowOwner: userWallet.publicKey,
         locker,
+        withdrawRecord,
         payer: userWallet.publicKey,
         systemProgram: web3.SystemProgram.programId,
       })
@@ -400,10 +416,103 @@ describe("Locked voter", () => {
         escrowOwner: voterProgram.provider.publicKey,
         escrowTokens: escrowATA,
         locker,
+        withdrawRecord,
         payer: voterProgram.provider.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: web3.SystemProgram.programId,
       })
       .rpc();
+
+    const withdrawRecordState =
+      await voterProgram.account.withdrawRecord.fetch(withdrawRecord);
+    expect(withdrawRecordState.locker.toBase58()).to.be.equal(
+      locker.toBase58()
+    );
+    expect(withdrawRecordState.owner.toBase58()).to.be.equal(
+      userWallet.publicKey.toBase58()
+    );
+  });
+
+  it("cannot create new escrow during relock cooldown", async () => {
+    const userWallet = new Wallet(withdrawnKeypair);
+    const voterProgram = createVoterProgram(userWallet, VOTER_PROGRAM_ID);
+    const [escrow, _bump] = deriveEscrow(locker, userWallet.publicKey);
+    const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+      locker,
+      userWallet.publicKey
+    );
+
+    await invokeAndAssertError(
+      () => {
+        return voterProgram.methods
+          .newEscrow()
+          .accounts({
+            escrow,
+            escrowOwner: userWallet.publicKey,
+            locker,
+            withdrawRecord,
+            payer: userWallet.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .rpc();
+      },
+      "Relock cooldown has not elapsed since the last withdrawal",
+      true
+    );
+  });
+
+  it("able to create new escrow after relock cooldown", async () => {
+    const userWallet = new Wallet(withdrawnKeypair);
+    const voterProgram = createVoterProgram(userWallet, VOTER_PROGRAM_ID);
+    const [escrow, _bump] = deriveEscrow(locker, userWallet.publicKey);
+    const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+      locker,
+      userWallet.publicKey
+    );
+
+    const withdrawRecordState =
+      await voterProgram.account.withdrawRecord.fetch(withdrawRecord);
+    const relockAllowedAt = withdrawRecordState.lastWithdrawnAt.add(
+      relockCooldownSeconds
+    );
+
+    while (true) {
+      const onchainTimestamp = await getOnChainTime(provider.connection);
+      if (relockAllowedAt.toNumber() > onchainTimestamp) {
+        console.log(
+          `${
+            relockAllowedAt.toNumber() - onchainTimestamp
+          } seconds until relock cooldown elapse`
+        );
+        await sleep(1000);
+      } else {
+        break;
+      }
+    }
+
+    await voterProgram.methods
+      .newEscrow()
+      .accounts({
+        escrow,
+        escrowOwner: userWallet.publicKey,
+        locker,
+        withdrawRecord,
+        payer: userWallet.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const escrowState = await voterProgram.account.escrow.fetch(escrow);
+    expect(escrowState.owner.toBase58()).to.be.equal(
+      userWallet.publicKey.toBase58()
+    );
+    expect(escrowState.amount.toString()).to.be.equal("0");
+
+    // the withdraw record is closed once the cooldown has elapsed
+    const withdrawRecordAccount = await provider.connection.getAccountInfo(
+      withdrawRecord
+    );
+    expect(withdrawRecordAccount).to.be.null;
   });
 
   it("users extend lock duration", async () => {
@@ -496,6 +605,7 @@ describe("Locked voter", () => {
       .accounts({
         governor: govern,
         governProgram: GOVERN_PROGRAM_ID,
+        tokenMint: rewardMint,
         locker,
         proposal,
         escrow,
@@ -508,6 +618,74 @@ describe("Locked voter", () => {
     const proposalState = await governProgram.account.proposal.fetch(proposal);
     expect(proposalState.activatedAt.toString()).not.equal("0");
     expect(proposalState.votingEndsAt.toString()).not.equal("0");
+    // min participation is disabled by default
+    expect(proposalState.minParticipationVotes.toString()).to.equal("0");
+  });
+
+  it("cannot activate an already activated proposal", async () => {
+    const keypair = userKeypairs[0];
+    const wallet = new Wallet(keypair);
+    const voterProgram = createVoterProgram(wallet, VOTER_PROGRAM_ID);
+
+    const [escrow, _bump] = deriveEscrow(locker, wallet.publicKey);
+
+    await invokeAndAssertError(
+      () => {
+        return voterProgram.methods
+          .activateProposal()
+          .accounts({
+            governor: govern,
+            governProgram: GOVERN_PROGRAM_ID,
+            tokenMint: rewardMint,
+            locker,
+            proposal,
+            escrow,
+            escrowOwner: wallet.publicKey,
+          })
+          .rpc();
+      },
+      "The proposal has already been activated.",
+      true
+    );
+  });
+
+  it("cannot activate a canceled proposal", async () => {
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const canceledProposal = await createProposal(govern, [], governProgram);
+
+    await governProgram.methods
+      .cancelProposal()
+      .accounts({
+        governor: govern,
+        proposal: canceledProposal,
+        proposer: wallet.publicKey,
+      })
+      .rpc();
+
+    const keypair = userKeypairs[0];
+    const userWallet = new Wallet(keypair);
+    const voterProgram = createVoterProgram(userWallet, VOTER_PROGRAM_ID);
+
+    const [escrow, _bump] = deriveEscrow(locker, userWallet.publicKey);
+
+    await invokeAndAssertError(
+      () => {
+        return voterProgram.methods
+          .activateProposal()
+          .accounts({
+            governor: govern,
+            governProgram: GOVERN_PROGRAM_ID,
+            tokenMint: rewardMint,
+            locker,
+            proposal: canceledProposal,
+            escrow,
+            escrowOwner: userWallet.publicKey,
+          })
+          .rpc();
+      },
+      "The proposal has been canceled.",
+      true
+    );
   });
 
   it("user #1 delegate voting power to new user and vote against a proposal", async () => {
@@ -683,6 +861,10 @@ describe("Locked voter", () => {
       const voterProgram = createVoterProgram(userWallet, VOTER_PROGRAM_ID);
 
       const [escrow, _bump] = deriveEscrow(locker, userWallet.publicKey);
+      const [withdrawRecord, _wBump] = deriveWithdrawRecord(
+        locker,
+        userWallet.publicKey
+      );
 
       while (true) {
         const [escrowState, onchainTimestamp] = await Promise.all([
@@ -726,8 +908,10 @@ describe("Locked voter", () => {
           escrowOwner: voterProgram.provider.publicKey,
           escrowTokens: escrowATA,
           locker,
+          withdrawRecord,
           payer: voterProgram.provider.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: web3.SystemProgram.programId,
         })
         .rpc();
 